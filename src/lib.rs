@@ -7,6 +7,9 @@
 //! to drive a display and allows a driver writer to focus on driving the display itself and only
 //! have to implement a single interface.
 
+#[cfg(feature = "hw-control")]
+use embedded_hal::delay::DelayNs;
+
 pub mod prelude;
 
 /// A ubiquitous error type for all kinds of problems which could happen when communicating with a
@@ -29,6 +32,12 @@ pub enum DisplayError {
 /// DI specific data format wrapper around slices of various widths
 /// Display drivers need to implement non-trivial conversions (e.g. with padding)
 /// as the hardware requires.
+///
+/// The `*Iter` variants wrap iterators instead of slices, so data can be streamed without a
+/// buffer holding all of it.
+///
+/// `U6`/`U9`/`U18` are high-bit-justified and must be packed MSB-first into the bus's native
+/// word width, returning `DataFormatNotImplemented` if a given width isn't supported.
 #[non_exhaustive]
 pub enum DataFormat<'a> {
     /// Slice of unsigned bytes
@@ -39,6 +48,18 @@ pub enum DataFormat<'a> {
     U16BE(&'a mut [u16]),
     /// Slice of unsigned 16bit values to be sent in little endian byte order
     U16LE(&'a mut [u16]),
+    /// Iterator over unsigned bytes
+    U8Iter(&'a mut dyn Iterator<Item = u8>),
+    /// Iterator over unsigned 16bit values to be sent in big endian byte order
+    U16BEIter(&'a mut dyn Iterator<Item = u16>),
+    /// Iterator over unsigned 16bit values to be sent in little endian byte order
+    U16LEIter(&'a mut dyn Iterator<Item = u16>),
+    /// Slice of unsigned 6bit values, high-bit-justified in each byte
+    U6(&'a [u8]),
+    /// Slice of unsigned 9bit values, high-bit-justified in each u16
+    U9(&'a [u16]),
+    /// Slice of unsigned 18bit values, high-bit-justified in each u32
+    U18(&'a [u32]),
 }
 
 impl<'a> From<&'a [u8]> for DataFormat<'a> {
@@ -60,6 +81,55 @@ pub trait WriteOnlyDataCommand {
     /// Send a batch of commands to display
     fn send_commands(&mut self, cmd: DataFormat<'_>) -> Result<(), DisplayError>;
 
-    /// Send pixel data to display
+    /// Send pixel data to display; `*Iter` variants should be drained through a fixed-size
+    /// stack buffer rather than collected, to keep streamed transfers at constant memory cost.
     fn send_data(&mut self, buf: DataFormat<'_>) -> Result<(), DisplayError>;
+
+    /// Send pixel data for the dirty rectangle `upper_left`..`lower_right` of a `disp_width`-wide
+    /// framebuffer; the default ignores the bounds and forwards to
+    /// [`send_data`](WriteOnlyDataCommand::send_data).
+    fn send_bounded_data(
+        &mut self,
+        buf: DataFormat<'_>,
+        _disp_width: u16,
+        _upper_left: (u16, u16),
+        _lower_right: (u16, u16),
+    ) -> Result<(), DisplayError> {
+        self.send_data(buf)
+    }
+}
+
+/// Async sibling of [`WriteOnlyDataCommand`] for bus implementations built on top of
+/// non-blocking, e.g. `embedded-hal-async`, SPI/I2C peripherals.
+///
+/// This trait is independent of [`WriteOnlyDataCommand`]; a bus type may implement either, or
+/// both, of the two depending on which peripherals it has available.
+#[cfg(feature = "async")]
+pub trait AsyncWriteOnlyDataCommand {
+    /// Send a batch of commands to display
+    fn send_commands(
+        &mut self,
+        cmd: DataFormat<'_>,
+    ) -> impl core::future::Future<Output = Result<(), DisplayError>>;
+
+    /// Send pixel data to display
+    fn send_data(
+        &mut self,
+        buf: DataFormat<'_>,
+    ) -> impl core::future::Future<Output = Result<(), DisplayError>>;
+}
+
+/// Hardware reset control for displays with a dedicated RST line.
+#[cfg(feature = "hw-control")]
+pub trait ResetInterface {
+    /// Drive the display's reset sequence, using `delay` for the timings between edges.
+    fn reset<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), DisplayError>;
+}
+
+/// Hardware BUSY pin polling for displays that must finish an internal operation before
+/// accepting the next command.
+#[cfg(feature = "hw-control")]
+pub trait BusyWaitInterface {
+    /// Block until the display's BUSY pin indicates it is ready.
+    fn busy_wait(&mut self) -> Result<(), DisplayError>;
 }
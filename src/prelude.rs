@@ -0,0 +1,11 @@
+//! Interface prelude
+//!
+//! Re-exports the traits and types needed to implement or use a display interface.
+
+pub use crate::{DataFormat, DisplayError, WriteOnlyDataCommand};
+
+#[cfg(feature = "async")]
+pub use crate::AsyncWriteOnlyDataCommand;
+
+#[cfg(feature = "hw-control")]
+pub use crate::{BusyWaitInterface, ResetInterface};